@@ -2,9 +2,9 @@
 use super::{PyInt, PyIntRef, PyTupleRef, PyTypeRef};
 use crate::{
     function::{FuncArgs, IntoPyObject, OptionalArg},
-    types::{Comparable, Constructor, Hashable, PyComparisonOp, Unhashable},
-    PyClassImpl, PyComparisonValue, PyContext, PyObjectRef, PyRef, PyResult, PyValue, TypeProtocol,
-    VirtualMachine,
+    types::{Comparable, Constructor, Hashable, PyComparisonOp},
+    PyClassImpl, PyComparisonValue, PyContext, PyHash, PyObjectRef, PyRef, PyResult, PyValue,
+    TypeProtocol, VirtualMachine,
 };
 use num_bigint::{BigInt, ToBigInt};
 use num_traits::{One, Signed, ToPrimitive, Zero};
@@ -193,6 +193,13 @@ impl PySlice {
         let (start, stop, step) = self.inner_indices(length, vm)?;
         Ok(vm.new_tuple((start, stop, step)))
     }
+
+    #[pymethod(magic)]
+    fn reduce(&self, vm: &VirtualMachine) -> PyTupleRef {
+        let cls = vm.ctx.types.slice_type.clone();
+        let args = vm.new_tuple((self.start(vm), self.stop(vm), self.step(vm)));
+        vm.new_tuple((cls, args))
+    }
 }
 
 impl Comparable for PySlice {
@@ -243,7 +250,16 @@ impl Comparable for PySlice {
     }
 }
 
-impl Unhashable for PySlice {}
+impl Hashable for PySlice {
+    fn hash(zelf: &PyRef<Self>, vm: &VirtualMachine) -> PyResult<PyHash> {
+        let elements = vm.new_tuple((
+            zelf.start_ref(vm).clone(),
+            zelf.stop.clone(),
+            zelf.step_ref(vm).clone(),
+        ));
+        vm.hash(elements.as_object())
+    }
+}
 
 /// A saturated slice with values ranging in [isize::MIN, isize::MAX]. Used for
 /// slicable sequences that require indices in the aforementioned range.
@@ -329,6 +345,162 @@ impl SaturatedSlice {
         };
         (range, step, is_negative_step)
     }
+
+    /// Returns an iterator over the concrete `usize` indices selected by this slice for a
+    /// sequence of length `len`, in selection order (i.e. respecting a negative step).
+    pub fn iter(&self, len: usize) -> SaturatedSliceIter {
+        let (range, step, is_negative_step) = self.adjust_indices(len);
+        SaturatedSliceIter::new(range, step, is_negative_step)
+    }
+}
+
+/// Iterator over the indices selected by a [`SaturatedSlice`], yielded in selection order.
+///
+/// Implements [`ExactSizeIterator`] and [`DoubleEndedIterator`] so it composes with
+/// `map`/`rev`/`collect` and can feed sequence construction directly.
+#[derive(Debug, Clone)]
+pub struct SaturatedSliceIter {
+    start: usize,
+    step: usize,
+    is_negative_step: bool,
+    remaining: usize,
+}
+
+impl SaturatedSliceIter {
+    fn new(range: Range<usize>, step: Option<usize>, is_negative_step: bool) -> Self {
+        let step = step.unwrap_or(1);
+        let remaining = if range.end > range.start {
+            (range.end - range.start + step - 1) / step
+        } else {
+            0
+        };
+        let start = if is_negative_step {
+            range.end.saturating_sub(1)
+        } else {
+            range.start
+        };
+        Self {
+            start,
+            step,
+            is_negative_step,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for SaturatedSliceIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let index = self.start;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.start = if self.is_negative_step {
+                self.start - self.step
+            } else {
+                self.start + self.step
+            };
+        }
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for SaturatedSliceIter {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl DoubleEndedIterator for SaturatedSliceIter {
+    fn next_back(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let index = if self.is_negative_step {
+            self.start - self.remaining * self.step
+        } else {
+            self.start + self.remaining * self.step
+        };
+        Some(index)
+    }
+}
+
+/// A subscript argument to a sequence's `__getitem__`/`__setitem__`, resolved to either a
+/// plain index or a slice, with one shared negative-index adjustment and bounds check.
+/// The sequence types' own `__getitem__`/`__setitem__` implementations do not go through
+/// it; each of them does its own "is it an int or a slice?" branching.
+#[derive(Clone)]
+pub enum SequenceIndex {
+    Int(isize),
+    Slice(PyRef<PySlice>),
+}
+
+impl SequenceIndex {
+    /// Converts a subscript object into a `SequenceIndex`, invoking `__index__` on
+    /// integer-like objects and accepting slices directly.
+    pub fn try_from_object(vm: &VirtualMachine, needle: PyObjectRef) -> PyResult<Self> {
+        match_class!(match needle {
+            i @ PyInt => Ok(SequenceIndex::Int(try_as_isize(&i, vm)?)),
+            slice @ PySlice => Ok(SequenceIndex::Slice(slice)),
+            obj => {
+                let i: PyIntRef = vm.to_index_opt(obj.clone()).unwrap_or_else(|| {
+                    Err(vm.new_type_error(format!(
+                        "sequence indices must be integers or slices, not {}",
+                        obj.class().name()
+                    )))
+                })?;
+                Ok(SequenceIndex::Int(try_as_isize(&i, vm)?))
+            }
+        })
+    }
+
+    /// Resolves an integer index against a sequence of length `len`, adjusting negative
+    /// indices from the end and raising `IndexError` if it is out of range. Unlike
+    /// `SaturatedSlice`'s indices, out-of-range here is an error rather than a saturation.
+    pub fn as_int_index(&self, vm: &VirtualMachine, len: usize) -> PyResult<usize> {
+        match self {
+            SequenceIndex::Int(index) => to_fixed_index(*index, len, vm),
+            SequenceIndex::Slice(_) => {
+                Err(vm.new_type_error("expected an int index, not a slice".to_owned()))
+            }
+        }
+    }
+
+    /// Returns the saturated slice this index selects, or `None` if it is a plain int.
+    pub fn as_slice(&self, vm: &VirtualMachine) -> PyResult<Option<SaturatedSlice>> {
+        match self {
+            SequenceIndex::Slice(slice) => Ok(Some(slice.to_saturated(vm)?)),
+            SequenceIndex::Int(_) => Ok(None),
+        }
+    }
+}
+
+fn try_as_isize(i: &PyIntRef, vm: &VirtualMachine) -> PyResult<isize> {
+    i.as_bigint()
+        .to_isize()
+        .ok_or_else(|| vm.new_index_error("cannot fit index into an isize".to_owned()))
+}
+
+// Equivalent to PySequence_GetItem's negative-index adjustment + bounds check.
+fn to_fixed_index(index: isize, len: usize, vm: &VirtualMachine) -> PyResult<usize> {
+    if index.is_negative() {
+        index
+            .checked_neg()
+            .and_then(|i| usize::try_from(i).ok())
+            .and_then(|i| len.checked_sub(i))
+    } else {
+        let index = index as usize;
+        (index < len).then_some(index)
+    }
+    .ok_or_else(|| vm.new_index_error("index out of range".to_owned()))
 }
 
 // Go from PyObjectRef to isize w/o overflow error, out of range values are substituted by